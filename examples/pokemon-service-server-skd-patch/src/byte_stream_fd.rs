@@ -0,0 +1,91 @@
+// TODO: PATCH - I/O-safe ByteStream construction from an owned descriptor
+
+use crate::types::ByteStream;
+
+use aws_smithy_types::byte_stream::{FsBuilder, Length};
+
+#[cfg(unix)]
+use std::os::fd::OwnedFd;
+#[cfg(windows)]
+use std::os::windows::io::OwnedHandle;
+
+/// fd-based constructors for [`ByteStream`], the siblings of the inherent
+/// `ByteStream::read_from()`. They live on a trait because a downstream crate
+/// cannot add inherent methods to `ByteStream`.
+pub trait ByteStreamFdExt {
+    /// Stream from an already-owned file descriptor — a pipe, `memfd`, or an
+    /// fd received over a socket — instead of a filesystem path.
+    ///
+    /// Ownership of `fd` moves into the returned builder and then into the
+    /// `ByteStream`, which closes it on drop; this upholds the `OwnedFd`
+    /// contract that the stream is its sole owner. For a seekable descriptor
+    /// (a regular file or `memfd`) the exact length is detected and forwarded,
+    /// matching the content-length the path builder derives. Retries differ:
+    /// the path builder recovers from read errors by reopening the path, which
+    /// a bare fd has no equivalent for, so an fd-sourced stream is not
+    /// retryable.
+    ///
+    /// Returns an error if the descriptor's metadata cannot be read.
+    #[cfg(unix)]
+    fn read_from_fd(fd: OwnedFd) -> std::io::Result<FsBuilder>;
+
+    /// Windows equivalent of [`read_from_fd`](Self::read_from_fd) taking an
+    /// owned `HANDLE`.
+    #[cfg(windows)]
+    fn read_from_handle(handle: OwnedHandle) -> std::io::Result<FsBuilder>;
+}
+
+impl ByteStreamFdExt for ByteStream {
+    #[cfg(unix)]
+    fn read_from_fd(fd: OwnedFd) -> std::io::Result<FsBuilder> {
+        // `std::fs::File` adopts the descriptor via `From<OwnedFd>`.
+        from_owned_file(std::fs::File::from(fd))
+    }
+
+    #[cfg(windows)]
+    fn read_from_handle(handle: OwnedHandle) -> std::io::Result<FsBuilder> {
+        from_owned_file(std::fs::File::from(handle))
+    }
+}
+
+#[cfg(any(unix, windows))]
+fn from_owned_file(std_file: std::fs::File) -> std::io::Result<FsBuilder> {
+    // Read metadata while we still hold the std handle: a seekable descriptor
+    // reports a concrete size, which we forward as the stream length so the
+    // behaviour lines up with the path builder. Non-seekable descriptors
+    // (pipes, sockets) have no meaningful length and are streamed without one.
+    let metadata = std_file.metadata()?;
+    let file = tokio::fs::File::from_std(std_file);
+    let mut builder = FsBuilder::new().file(file);
+    if metadata.is_file() {
+        builder = builder.length(Length::Exact(metadata.len()));
+    }
+    Ok(builder)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::os::fd::OwnedFd;
+
+    #[tokio::test]
+    async fn read_from_fd_streams_the_descriptor_contents() {
+        let payload = b"owned fd payload";
+
+        // Write a file, reopen it read-only, and surrender the fd to the
+        // builder — which becomes the sole owner and closes it on drop.
+        let path = std::env::temp_dir().join("bytestream_read_from_fd.bin");
+        std::fs::File::create(&path)
+            .unwrap()
+            .write_all(payload)
+            .unwrap();
+        let fd = OwnedFd::from(std::fs::File::open(&path).unwrap());
+
+        let stream = ByteStream::read_from_fd(fd).unwrap().build().await.unwrap();
+        let collected = stream.collect().await.unwrap().into_bytes();
+
+        assert_eq!(&collected[..], payload);
+        std::fs::remove_file(&path).ok();
+    }
+}
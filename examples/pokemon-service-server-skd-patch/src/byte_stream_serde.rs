@@ -0,0 +1,163 @@
+// TODO: PATCH - opt-in serde support for buffered bodies
+//
+// This whole module is gated behind the crate's `serde` feature so serde stays
+// an optional dependency. The crate manifest supplies the wiring:
+//
+//     [dependencies]
+//     serde = { version = "1", optional = true }
+//
+//     [features]
+//     serde = ["dep:serde"]
+//
+// A streaming `ByteStream` is not re-readable, so support is offered for its
+// already-collected form, `AggregatedBytes` (the result of `.collect()`).
+#![cfg(feature = "serde")]
+
+use crate::types::AggregatedBytes;
+
+use bytes::Bytes;
+use serde::de::Visitor;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// A wrapped-then-serialized snapshot of an `AggregatedBytes`: serde can't be
+/// implemented on `AggregatedBytes` from here, so the payload is copied into
+/// this newtype. It encodes as a byte string via `serialize_bytes` (mirroring
+/// serde's `Bytes`/`ByteBuf`) so CBOR/MessagePack stay compact instead of
+/// emitting an integer sequence.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SerializableAggregatedBytes(Bytes);
+
+impl SerializableAggregatedBytes {
+    /// Wrap an already-collected payload.
+    pub fn from_bytes(bytes: Bytes) -> Self {
+        Self(bytes)
+    }
+
+    /// Borrow the buffered payload.
+    pub fn as_bytes(&self) -> &Bytes {
+        &self.0
+    }
+
+    /// Take ownership of the buffered payload, e.g. to feed it back into a
+    /// fresh `ByteStream`.
+    pub fn into_bytes(self) -> Bytes {
+        self.0
+    }
+}
+
+impl From<AggregatedBytes> for SerializableAggregatedBytes {
+    fn from(aggregated: AggregatedBytes) -> Self {
+        Self(aggregated.into_bytes())
+    }
+}
+
+impl Serialize for SerializableAggregatedBytes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for SerializableAggregatedBytes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_byte_buf(BytesVisitor)
+    }
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = SerializableAggregatedBytes;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a byte array")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(SerializableAggregatedBytes(Bytes::copy_from_slice(v)))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(SerializableAggregatedBytes(Bytes::from(v)))
+    }
+
+    // Formats that model a byte string as a sequence (e.g. JSON) fall through
+    // to here; collect it element by element.
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut buf = match seq.size_hint() {
+            Some(len) => Vec::with_capacity(len),
+            None => Vec::new(),
+        };
+        while let Some(byte) = seq.next_element()? {
+            buf.push(byte);
+        }
+        Ok(SerializableAggregatedBytes(Bytes::from(buf)))
+    }
+}
+
+// Readable, bounded rendering: show the first chunk as a hex/ASCII pair and a
+// total length rather than dumping a potentially huge payload.
+impl fmt::Debug for SerializableAggregatedBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const MAX: usize = 32;
+        let shown = &self.0[..self.0.len().min(MAX)];
+
+        let mut hex = String::with_capacity(shown.len() * 2);
+        for byte in shown {
+            hex.push_str(&format!("{byte:02x}"));
+        }
+
+        let ascii: String = shown
+            .iter()
+            .map(|&b| if b.is_ascii_graphic() || b == b' ' { b as char } else { '.' })
+            .collect();
+
+        let ellipsis = if self.0.len() > MAX { "..." } else { "" };
+        write!(
+            f,
+            "SerializableAggregatedBytes {{ len: {}, hex: \"{hex}{ellipsis}\", ascii: \"{ascii}{ellipsis}\" }}",
+            self.0.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_test::{assert_tokens, Token};
+
+    #[test]
+    fn round_trips_as_a_byte_string() {
+        let value = SerializableAggregatedBytes(Bytes::from_static(b"payload"));
+        // Serializes as a single byte string, not a sequence of integers, and
+        // deserializes back through the byte-string visitor path.
+        assert_tokens(&value, &[Token::Bytes(b"payload")]);
+    }
+
+    #[test]
+    fn debug_truncates_long_payloads() {
+        let value = SerializableAggregatedBytes(Bytes::from(vec![b'a'; 40]));
+        let rendered = format!("{value:?}");
+
+        assert!(rendered.contains("len: 40"), "{rendered}");
+        assert!(rendered.contains("..."), "{rendered}");
+        // Only the first 32 bytes (64 hex chars) are rendered.
+        assert!(rendered.contains(&"61".repeat(32)), "{rendered}");
+        assert!(!rendered.contains(&"61".repeat(33)), "{rendered}");
+    }
+}
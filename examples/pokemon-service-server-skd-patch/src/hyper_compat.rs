@@ -2,6 +2,15 @@
 
 use crate::types::ByteStream;
 
+use aws_smithy_types::byte_stream::error::Error as ByteStreamError;
+use bytes::Bytes;
+use futures_core::Stream;
+use http::HeaderMap;
+use http_body_1x::{Body, Frame, SizeHint};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
 // Newtype wrapper to work around orphan rules
 #[derive(Debug)]
 pub struct HyperIncoming(pub hyper::body::Incoming);
@@ -18,6 +27,88 @@ impl From<hyper::body::Incoming> for HyperIncoming {
     }
 }
 
+impl HyperIncoming {
+    /// Convert into a `ByteStream` while retaining any trailing `HeaderMap`.
+    ///
+    /// `from_body_1_x` only carries data frames across the shim, so trailer
+    /// frames — the ones S3 uses to deliver `x-amz-checksum-*` integrity
+    /// values — are otherwise lost. The returned [`Trailers`] handle shares a
+    /// slot with the stream and is populated once the `ByteStream` has been
+    /// fully drained.
+    pub fn into_byte_stream_with_trailers(self) -> (ByteStream, Trailers) {
+        let slot = Arc::new(Mutex::new(None));
+        let body = CaptureTrailers {
+            inner: self.0,
+            slot: Arc::clone(&slot),
+        };
+        (ByteStream::from_body_1_x(body), Trailers { slot })
+    }
+}
+
+// Body shim that forwards data frames untouched while stashing the trailing
+// `HeaderMap` into a shared slot so it survives the `from_body_1_x` conversion.
+// Generic over the wrapped body so it can be exercised with a fake trailer
+// source in tests, not just `hyper::body::Incoming`.
+struct CaptureTrailers<B> {
+    inner: B,
+    slot: Arc<Mutex<Option<HeaderMap>>>,
+}
+
+impl<B> Body for CaptureTrailers<B>
+where
+    B: Body<Data = Bytes> + Unpin,
+{
+    type Data = Bytes;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.get_mut();
+        match Pin::new(&mut this.inner).poll_frame(cx) {
+            Poll::Ready(Some(Ok(frame))) => {
+                if frame.is_trailers() {
+                    match frame.into_trailers() {
+                        Ok(trailers) => {
+                            *this.slot.lock().unwrap() = Some(trailers);
+                            Poll::Ready(None)
+                        }
+                        // `is_trailers()` was true, so this arm is unreachable.
+                        Err(frame) => Poll::Ready(Some(Ok(frame))),
+                    }
+                } else {
+                    Poll::Ready(Some(Ok(frame)))
+                }
+            }
+            other => other,
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+/// Accessor for the trailing `HeaderMap` of a `ByteStream` built from a
+/// [`HyperIncoming`]. Returns `None` until the stream has been fully drained
+/// and `Some` afterwards if the peer sent trailers.
+#[derive(Clone, Debug)]
+pub struct Trailers {
+    slot: Arc<Mutex<Option<HeaderMap>>>,
+}
+
+impl Trailers {
+    /// Retrieve the captured trailers, cloning them out of the shared slot.
+    pub fn get(&self) -> Option<HeaderMap> {
+        self.slot.lock().unwrap().clone()
+    }
+}
+
 // Provide a blanket implementation for hyper::body::Incoming -> ByteStream
 // by going through our newtype
 impl From<hyper::body::Incoming> for ByteStream {
@@ -25,3 +116,174 @@ impl From<hyper::body::Incoming> for ByteStream {
         HyperIncoming::from(body).into()
     }
 }
+
+// Wraps a `ByteStream` so it can be consumed as an http-body 1.0 `Body`. Each
+// chunk produced by the underlying stream is surfaced as a data `Frame`; the
+// stream is driven lazily so nothing is buffered up front.
+#[derive(Debug)]
+pub struct SdkBodyAsHttpBody1x {
+    inner: ByteStream,
+}
+
+impl SdkBodyAsHttpBody1x {
+    fn new(inner: ByteStream) -> Self {
+        Self { inner }
+    }
+}
+
+impl Body for SdkBodyAsHttpBody1x {
+    type Data = Bytes;
+    type Error = ByteStreamError;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.get_mut();
+        match Pin::new(&mut this.inner).poll_next(cx) {
+            Poll::Ready(Some(Ok(data))) => Poll::Ready(Some(Ok(Frame::data(data)))),
+            Poll::Ready(Some(Err(err))) => Poll::Ready(Some(Err(err))),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        // `ByteStream::size_hint()` returns smithy's own `SizeHint`, which
+        // exposes `.exact()`/`.lower()`/`.upper()` accessors rather than tuple
+        // fields; translate it into the http-body 1.0 `SizeHint`.
+        let sh = self.inner.size_hint();
+        match sh.exact() {
+            Some(exact) => SizeHint::with_exact(exact),
+            None => {
+                let mut hint = SizeHint::new();
+                hint.set_lower(sh.lower());
+                if let Some(upper) = sh.upper() {
+                    hint.set_upper(upper);
+                }
+                hint
+            }
+        }
+    }
+}
+
+/// `into_body_1_x` is the inverse of the inherent `ByteStream::from_body_1_x`,
+/// living on a trait because a downstream crate can't add inherent methods to
+/// `ByteStream`.
+pub trait ByteStreamExt {
+    /// Consume the `ByteStream` as an http-body 1.0 `Body` without buffering.
+    fn into_body_1_x(self) -> SdkBodyAsHttpBody1x;
+}
+
+impl ByteStreamExt for ByteStream {
+    fn into_body_1_x(self) -> SdkBodyAsHttpBody1x {
+        SdkBodyAsHttpBody1x::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::HeaderValue;
+    use std::task::{RawWaker, RawWakerVTable, Waker};
+
+    fn noop_waker() -> Waker {
+        const VTABLE: RawWakerVTable =
+            RawWakerVTable::new(|_| RAW, |_| {}, |_| {}, |_| {});
+        const RAW: RawWaker = RawWaker::new(std::ptr::null(), &VTABLE);
+        // SAFETY: the vtable's clone/wake/drop are all no-ops over a null data
+        // pointer, so there is nothing to invalidate.
+        unsafe { Waker::from_raw(RAW) }
+    }
+
+    #[test]
+    fn into_body_1_x_streams_data_and_size_hint() {
+        let payload = b"hello trailers";
+        let mut body = ByteStream::from_static(payload).into_body_1_x();
+
+        // The exact length is carried across from the `ByteStream`.
+        assert_eq!(body.size_hint().exact(), Some(payload.len() as u64));
+
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        let mut collected = Vec::new();
+        loop {
+            match Pin::new(&mut body).poll_frame(&mut cx) {
+                Poll::Ready(Some(Ok(frame))) => {
+                    let data = frame.into_data().expect("only data frames");
+                    collected.extend_from_slice(&data);
+                }
+                Poll::Ready(Some(Err(err))) => panic!("unexpected error: {err}"),
+                Poll::Ready(None) => break,
+                Poll::Pending => panic!("an in-memory stream is always ready"),
+            }
+        }
+        assert_eq!(collected, payload);
+    }
+
+    // Body that yields one data frame, then a trailer frame, then end-of-stream.
+    struct FakeTrailerBody {
+        data: Option<Bytes>,
+        trailers: Option<HeaderMap>,
+    }
+
+    impl Body for FakeTrailerBody {
+        type Data = Bytes;
+        type Error = std::convert::Infallible;
+
+        fn poll_frame(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+            let this = self.get_mut();
+            if let Some(data) = this.data.take() {
+                return Poll::Ready(Some(Ok(Frame::data(data))));
+            }
+            if let Some(trailers) = this.trailers.take() {
+                return Poll::Ready(Some(Ok(Frame::trailers(trailers))));
+            }
+            Poll::Ready(None)
+        }
+    }
+
+    #[test]
+    fn capture_trailers_stashes_the_trailing_header_map() {
+        let mut trailers = HeaderMap::new();
+        trailers.insert("x-amz-checksum-crc32", HeaderValue::from_static("abcd1234"));
+
+        let slot = Arc::new(Mutex::new(None));
+        let mut body = CaptureTrailers {
+            inner: FakeTrailerBody {
+                data: Some(Bytes::from_static(b"body")),
+                trailers: Some(trailers),
+            },
+            slot: Arc::clone(&slot),
+        };
+
+        // Slot is empty until the stream is drained.
+        assert!(slot.lock().unwrap().is_none());
+
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        // First frame is the forwarded data.
+        match Pin::new(&mut body).poll_frame(&mut cx) {
+            Poll::Ready(Some(Ok(frame))) => {
+                assert_eq!(&frame.into_data().unwrap()[..], b"body");
+            }
+            other => panic!("expected data frame, got {other:?}"),
+        }
+
+        // The trailer frame is swallowed into the slot and ends the stream.
+        match Pin::new(&mut body).poll_frame(&mut cx) {
+            Poll::Ready(None) => {}
+            other => panic!("expected end-of-stream after trailers, got {other:?}"),
+        }
+
+        let captured = slot.lock().unwrap().clone().expect("trailers captured");
+        assert_eq!(
+            captured.get("x-amz-checksum-crc32").unwrap(),
+            "abcd1234"
+        );
+    }
+}